@@ -1,5 +1,6 @@
 use anyhow::{anyhow, Result};
 use eframe::egui;
+use process::Format;
 use tracer::{EventCollector, LogUi};
 use tracing::{event, Level};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -29,6 +30,8 @@ fn main() -> Result<()> {
 #[derive(Debug)]
 struct App {
     tracer_collector: EventCollector,
+    strict_validation: bool,
+    format: Format,
 }
 
 impl eframe::App for App {
@@ -36,18 +39,58 @@ impl eframe::App for App {
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("Converter");
             ui.add_space(16.0);
+            ui.checkbox(
+                &mut self.strict_validation,
+                "Validate against Skill schema (strict)",
+            );
+            ui.add_space(8.0);
             ui.horizontal(|ui| {
-                if ui.button("Convert to CSV").clicked()
+                egui::ComboBox::from_label("Output format")
+                    .selected_text(self.format.to_string())
+                    .show_ui(ui, |ui| {
+                        for format in Format::ALL {
+                            ui.selectable_value(&mut self.format, format, format.to_string());
+                        }
+                    });
+                if ui.button("Convert").clicked()
                     || ctx.input(|i| i.key_pressed(egui::Key::Enter))
                 {
-                    match process::to_json() {
+                    match process::convert(self.format, self.strict_validation) {
                         Ok(path) => event!(Level::INFO, "Wrote {} to file.", path),
                         Err(e) => event!(Level::ERROR, "{:#?}", e),
                     }
                 }
+                if ui.button("Convert to JSON").clicked() {
+                    match process::from_csv() {
+                        Ok(path) => event!(Level::INFO, "Wrote {} to file.", path),
+                        Err(e) => event!(Level::ERROR, "{:#?}", e),
+                    }
+                }
+                if ui.button("Convert Folder").clicked() {
+                    if let Err(e) = process::convert_folder(self.format, self.strict_validation) {
+                        event!(Level::ERROR, "{:#?}", e);
+                    }
+                }
             });
             ui.separator();
             LogUi::new(self.tracer_collector.clone()).ui(ui);
+            ui.horizontal(|ui| {
+                if ui.button("Export Log").clicked() {
+                    if let Some(path) = rfd::FileDialog::new()
+                        .add_filter("JSON lines", &["jsonl"])
+                        .add_filter("CSV", &["csv"])
+                        .set_file_name("converter.jsonl")
+                        .save_file()
+                    {
+                        if let Err(e) = self.tracer_collector.export(path) {
+                            event!(Level::ERROR, "{:#?}", e);
+                        }
+                    }
+                }
+                if ui.button("Clear Log").clicked() {
+                    self.tracer_collector.clear();
+                }
+            });
         });
     }
 }
@@ -56,6 +99,8 @@ impl App {
     fn with_collector(collector: EventCollector) -> Self {
         Self {
             tracer_collector: collector,
+            strict_validation: true,
+            format: Format::Csv,
         }
     }
 }
@@ -1,7 +1,8 @@
 use anyhow::{anyhow, Result};
-use csv::WriterBuilder;
+use csv::{ReaderBuilder, WriterBuilder};
 use rfd::FileDialog;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::HashMap;
 use tracing::{event, Level};
 
@@ -146,34 +147,338 @@ pub struct Skill {
     aspects: Vec<String>,
 }
 
-pub fn to_json() -> Result<String> {
+/// Validates `file` against the typed [`Skill`] schema before it's flattened,
+/// so malformed records (an unknown `type` variant, a bad `proficiencyLevels`
+/// map, ...) are reported with the serde error instead of silently producing
+/// a garbage CSV. Accepts either a single `Skill` object or a `Skill` array.
+fn validate_skill_schema(file: &str) -> Result<()> {
+    let value: Value = serde_json::from_str(file)?;
+    let result = if value.is_array() {
+        serde_json::from_value::<Vec<Skill>>(value).map(|_| ())
+    } else {
+        serde_json::from_value::<Skill>(value).map(|_| ())
+    };
+
+    if let Err(e) = result {
+        event!(Level::ERROR, "Skill schema validation failed: {e}");
+        return Err(anyhow!("Skill schema validation failed: {e}"));
+    }
+    Ok(())
+}
+
+/// Either shape a validated input file can take: a lone record, or an array
+/// of them. Kept untagged so the serialized output has the same top-level
+/// shape the source JSON had, except in BSON, which needs a document at the
+/// top level and so stores an array under a `skills` key.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum ParsedSkills {
+    One(Skill),
+    Many(Vec<Skill>),
+}
+
+impl ParsedSkills {
+    fn parse(file: &str) -> Result<Self> {
+        let value: Value = serde_json::from_str(file)?;
+        if value.is_array() {
+            Ok(Self::Many(serde_json::from_value(value)?))
+        } else {
+            Ok(Self::One(serde_json::from_value(value)?))
+        }
+    }
+}
+
+/// Output serialization backend for [`convert`]. `Csv` keeps flattening
+/// arbitrary JSON through `Flattener`; every other variant serializes the
+/// validated `Skill` schema through its matching serde backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    Json,
+    Bincode,
+    MessagePack,
+    Bson,
+    Pot,
+}
+
+impl Format {
+    pub const ALL: [Format; 6] = [
+        Format::Csv,
+        Format::Json,
+        Format::Bincode,
+        Format::MessagePack,
+        Format::Bson,
+        Format::Pot,
+    ];
+
+    fn extension(self) -> &'static str {
+        match self {
+            Format::Csv => "csv",
+            Format::Json => "json",
+            Format::Bincode => "bin",
+            Format::MessagePack => "msgpack",
+            Format::Bson => "bson",
+            Format::Pot => "pot",
+        }
+    }
+
+    /// Every backend except CSV (JSON included) serializes the typed `Skill`
+    /// schema, so they always need it regardless of the strict/lenient toggle.
+    fn requires_typed_skill(self) -> bool {
+        !matches!(self, Format::Csv)
+    }
+}
+
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Format::Csv => "CSV",
+            Format::Json => "JSON",
+            Format::Bincode => "Bincode",
+            Format::MessagePack => "MessagePack",
+            Format::Bson => "BSON",
+            Format::Pot => "Pot",
+        };
+        write!(f, "{name}")
+    }
+}
+
+fn flatten_to_csv(file: &str) -> Result<Vec<u8>> {
     use flatten_json_object::{ArrayFormatting, Flattener};
     use json_objects_to_csv::Json2Csv;
 
+    let flattener = Flattener::new()
+        .set_key_separator(".")
+        .set_array_formatting(ArrayFormatting::Plain)
+        .set_preserve_empty_arrays(true)
+        .set_preserve_empty_objects(true);
+    let mut output = vec![];
+    let writer = WriterBuilder::new().from_writer(&mut output);
+    Json2Csv::new(flattener).convert_from_reader(file.as_bytes(), writer)?;
+    Ok(output)
+}
+
+fn serialize_skills(file: &str, format: Format) -> Result<Vec<u8>> {
+    let parsed = ParsedSkills::parse(file)?;
+    match format {
+        Format::Csv => unreachable!("Csv is handled by flatten_to_csv"),
+        Format::Json => Ok(serde_json::to_vec_pretty(&parsed)?),
+        Format::Bincode => Ok(bincode::serialize(&parsed)?),
+        Format::MessagePack => Ok(rmp_serde::to_vec(&parsed)?),
+        Format::Bson => {
+            let document = match &parsed {
+                ParsedSkills::One(skill) => bson::to_document(skill)?,
+                ParsedSkills::Many(skills) => bson::doc! { "skills": bson::to_bson(skills)? },
+            };
+            let mut bytes = vec![];
+            document.to_writer(&mut bytes)?;
+            Ok(bytes)
+        }
+        Format::Pot => Ok(pot::to_vec(&parsed)?),
+    }
+}
+
+/// Validates (if required) and converts a single JSON file already resolved
+/// to a path, writing the result alongside it with `format`'s extension.
+/// Shared by the single-file and batch-folder entry points.
+fn convert_path(path: std::path::PathBuf, format: Format, strict: bool) -> Result<String> {
+    event!(Level::INFO, "Reading from {:#?}.", path);
+    let file = std::fs::read_to_string(path.clone())?;
+    event!(Level::DEBUG, "{file:#?}");
+
+    if strict || format.requires_typed_skill() {
+        validate_skill_schema(&file)?;
+    }
+
+    let output = match format {
+        Format::Csv => flatten_to_csv(&file)?,
+        _ => serialize_skills(&file, format)?,
+    };
+    event!(Level::DEBUG, "{} bytes written", output.len());
+
+    let mut out_path = path.clone();
+    out_path.set_extension(format.extension());
+    if out_path == path {
+        // The chosen format's extension matches the source file's, so writing
+        // in place would silently clobber (and lossily re-serialize) the
+        // original. Write alongside it instead.
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        out_path.set_file_name(format!("{stem}.converted.{}", format.extension()));
+    }
+    std::fs::write(out_path.clone(), output)?;
+    Ok(out_path.to_str().unwrap_or_default().to_string())
+}
+
+/// Reads a file the user picks, validates it against the `Skill` schema
+/// (always for every format except CSV, and for CSV only when `strict`), and
+/// writes it out in `format`. Returns the path written to.
+pub fn convert(format: Format, strict: bool) -> Result<String> {
     event!(Level::DEBUG, "Reading file");
-    let Some(mut path) = FileDialog::new()
+    let Some(path) = FileDialog::new()
         .add_filter("text/json", &["json"])
         .pick_file()
     else {
         return Err(anyhow!("No file selected."));
     };
+    convert_path(path, format, strict)
+}
+
+/// Summary of a "Convert Folder" run: how many `*.json` files were found,
+/// how many converted cleanly, how many failed, and the aggregate time
+/// spent converting.
+#[derive(Debug, Default)]
+pub struct BatchSummary {
+    pub total: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub elapsed: std::time::Duration,
+}
+
+/// Lets the user pick a folder, converts every `*.json` file in it (walked
+/// recursively) to `format`, and returns an aggregate summary. Files named
+/// `*.converted.json`, earlier outputs of this tool, are skipped. A single
+/// file's failure is logged and counted, not propagated, so the rest of the
+/// batch still runs.
+pub fn convert_folder(format: Format, strict: bool) -> Result<BatchSummary> {
+    let Some(folder) = FileDialog::new().pick_folder() else {
+        return Err(anyhow!("No folder selected."));
+    };
+    event!(Level::INFO, "Converting folder {:#?}.", folder);
+
+    let mut summary = BatchSummary::default();
+    for entry in walkdir::WalkDir::new(&folder)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+        .filter(|entry| {
+            !entry
+                .file_name()
+                .to_string_lossy()
+                .ends_with(".converted.json")
+        })
+    {
+        let path = entry.path().to_path_buf();
+        let start = std::time::Instant::now();
+        let result = convert_path(path.clone(), format, strict);
+        let elapsed = start.elapsed();
+        summary.total += 1;
+        summary.elapsed += elapsed;
+
+        match result {
+            Ok(written) => {
+                summary.succeeded += 1;
+                event!(Level::INFO, "Converted {:#?} in {:?} -> {}", path, elapsed, written);
+            }
+            Err(e) => {
+                summary.failed += 1;
+                event!(Level::ERROR, "Failed to convert {:#?} in {:?}: {:#?}", path, elapsed, e);
+            }
+        }
+    }
+
+    event!(
+        Level::INFO,
+        "Converted folder: {} succeeded, {} failed, {} total, {:?} aggregate.",
+        summary.succeeded,
+        summary.failed,
+        summary.total,
+        summary.elapsed
+    );
+    Ok(summary)
+}
+
+pub fn from_csv() -> Result<String> {
+    event!(Level::DEBUG, "Reading file");
+    let Some(mut path) = FileDialog::new()
+        .add_filter("text/csv", &["csv"])
+        .pick_file()
+    else {
+        return Err(anyhow!("No file selected."));
+    };
     event!(Level::INFO, "Reading from {:#?}.", path);
     let file = std::fs::read_to_string(path.clone())?;
     event!(Level::DEBUG, "{file:#?}");
 
-    let flattener = Flattener::new()
-        .set_key_separator(".")
-        .set_array_formatting(ArrayFormatting::Plain)
-        .set_preserve_empty_arrays(true)
-        .set_preserve_empty_objects(true);
-    let mut output = vec![];
-    let writer = WriterBuilder::new().from_writer(&mut output);
-    Json2Csv::new(flattener).convert_from_reader(file.as_bytes(), writer)?;
-    let output = std::str::from_utf8(&output)?;
+    let mut reader = ReaderBuilder::new().from_reader(file.as_bytes());
+    let headers = reader.headers()?.clone();
 
+    let mut rows = vec![];
+    for record in reader.records() {
+        let record = record?;
+        let mut row = Value::Null;
+        for (header, cell) in headers.iter().zip(record.iter()) {
+            // Only the preserved-empty `[]`/`{}` sentinels take the
+            // container path; a genuinely empty cell is a `""` field and
+            // must still be written so the record re-validates.
+            let segments: Vec<&str> = header.split('.').collect();
+            set_path(&mut row, &segments, coerce_cell(cell));
+        }
+        rows.push(row);
+    }
+
+    // `Json2Csv` emits one CSV row per top-level JSON document; a single row
+    // means the source was a lone object, not a one-element array, so unwrap
+    // it to round-trip the original shape faithfully.
+    let output = match <[Value; 1]>::try_from(rows) {
+        Ok([row]) => row,
+        Err(rows) => serde_json::to_value(rows)?,
+    };
     event!(Level::DEBUG, "{output:#?}");
 
-    path.set_extension("csv");
-    std::fs::write(path.clone(), output)?;
+    path.set_extension("json");
+    std::fs::write(path.clone(), serde_json::to_string_pretty(&output)?)?;
     Ok(path.to_str().unwrap_or_default().to_string())
 }
+
+/// Walks `segments` into `root`, creating arrays for integer segments and
+/// objects for named ones, and sets the final segment to `value`.
+fn set_path(root: &mut Value, segments: &[&str], value: Value) {
+    let Some((head, rest)) = segments.split_first() else {
+        *root = value;
+        return;
+    };
+
+    if let Ok(index) = head.parse::<usize>() {
+        if !root.is_array() {
+            *root = Value::Array(vec![]);
+        }
+        let array = root.as_array_mut().expect("just coerced to array");
+        if array.len() <= index {
+            array.resize(index + 1, Value::Null);
+        }
+        set_path(&mut array[index], rest, value);
+    } else {
+        if !root.is_object() {
+            *root = Value::Object(serde_json::Map::new());
+        }
+        let object = root.as_object_mut().expect("just coerced to object");
+        set_path(object.entry(*head).or_insert(Value::Null), rest, value);
+    }
+}
+
+/// Coerces a CSV cell back to the JSON value it was flattened from, falling
+/// back to a plain string when the round-trip through a richer type isn't
+/// exact. Mirrors `ArrayFormatting::Plain`'s literal `[]`/`{}` for the
+/// preserved-empty case.
+fn coerce_cell(cell: &str) -> Value {
+    match cell {
+        "[]" => return Value::Array(vec![]),
+        "{}" => return Value::Object(serde_json::Map::new()),
+        "true" => return Value::Bool(true),
+        "false" => return Value::Bool(false),
+        _ => {}
+    }
+    if let Ok(i) = cell.parse::<i64>() {
+        if i.to_string() == cell {
+            return Value::Number(i.into());
+        }
+    }
+    if let Ok(f) = cell.parse::<f64>() {
+        if f.to_string() == cell {
+            if let Some(number) = serde_json::Number::from_f64(f) {
+                return Value::Number(number);
+            }
+        }
+    }
+    Value::String(cell.to_string())
+}
@@ -1,4 +1,7 @@
+use anyhow::Result;
+use serde::Serialize;
 use std::fmt::Debug;
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 use tracing::{Event, Level, Subscriber};
 use tracing_subscriber::layer::Context;
@@ -9,6 +12,31 @@ mod state;
 pub mod ui_tracer;
 use state::{CollectedEvent, TracerLevel};
 
+/// Flat, serializable projection of a [`CollectedEvent`], used when
+/// exporting the log to JSON or CSV.
+#[derive(Debug, Serialize)]
+struct LogRecord {
+    timestamp: String,
+    level: String,
+    target: String,
+    message: String,
+}
+
+impl From<&CollectedEvent> for LogRecord {
+    fn from(event: &CollectedEvent) -> Self {
+        Self {
+            // `CollectedEvent::timestamp` is stamped with `SystemTime::now()`
+            // at collection time; format it as RFC 3339 so the export is a
+            // stable, parseable timestamp rather than Rust's internal debug
+            // representation.
+            timestamp: humantime::format_rfc3339(event.timestamp).to_string(),
+            level: format!("{:?}", event.level),
+            target: event.target.clone(),
+            message: event.message.clone(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct EventCollector {
     level: Level,
@@ -27,7 +55,7 @@ impl EventCollector {
         self.events.lock().unwrap().clone()
     }
 
-    fn clear(&self) {
+    pub fn clear(&self) {
         let mut events = self.events.lock().unwrap();
         *events = vec![];
     }
@@ -39,6 +67,29 @@ impl EventCollector {
             self.events.lock().unwrap().push(event);
         }
     }
+
+    /// Exports all collected events to `path`. Writes CSV when the path ends
+    /// in `.csv`, newline-delimited JSON otherwise.
+    pub fn export(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let records: Vec<LogRecord> = self.events().iter().map(LogRecord::from).collect();
+
+        if path.extension().is_some_and(|ext| ext == "csv") {
+            let mut writer = csv::Writer::from_path(path)?;
+            for record in &records {
+                writer.serialize(record)?;
+            }
+            writer.flush()?;
+        } else {
+            let mut lines = String::new();
+            for record in &records {
+                lines.push_str(&serde_json::to_string(record)?);
+                lines.push('\n');
+            }
+            std::fs::write(path, lines)?;
+        }
+        Ok(())
+    }
 }
 
 impl<S> Layer<S> for EventCollector